@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::PriorityQueue;
 use std::fmt;
@@ -127,6 +128,363 @@ impl<'a, T, V> Graph<'a, T, V> {
     pub fn node_count(&self) -> uint {
         self.nodes.len()
     }
+
+    /// Labels each node with the identifier of the weakly connected component it
+    /// belongs to, treating edges as undirected.
+    ///
+    /// Two nodes share a label if there is a path between them following edges in
+    /// either direction. Labels are compacted to a contiguous range starting at `0`.
+    pub fn connected_components(&self) -> Vec<uint> {
+        let mut uf = UnionFind::new(self.node_count());
+        for from in range(0, self.node_count()) {
+            for edge in self.connections(from).iter() {
+                uf.union(from, edge.dest);
+            }
+        }
+
+        let mut labels = Vec::from_elem(self.node_count(), 0u);
+        let mut roots_to_labels: HashMap<uint, uint> = HashMap::new();
+        let mut next_label = 0u;
+        for node in range(0, self.node_count()) {
+            let root = uf.find(node);
+            let label = if roots_to_labels.contains_key(&root) {
+                *roots_to_labels.get(&root)
+            } else {
+                roots_to_labels.insert(root, next_label);
+                next_label += 1;
+                next_label - 1
+            };
+            *labels.get_mut(node) = label;
+        }
+        labels
+    }
+
+    /// The number of distinct weakly connected components in the graph.
+    pub fn component_count(&self) -> uint {
+        let mut distinct = HashSet::new();
+        for label in self.connected_components().iter() {
+            distinct.insert(*label);
+        }
+        distinct.len()
+    }
+
+    /// Produces a topological ordering of the graph's nodes.
+    ///
+    /// Uses Kahn's algorithm: nodes with no remaining incoming edges are repeatedly
+    /// removed and appended to the output, decrementing the in-degree of their
+    /// successors as they go. If a cycle prevents every node from being ordered,
+    /// returns `Err` containing a node that participates in a cycle.
+    pub fn toposort(&self) -> Result<Vec<NodeIdentifier>, NodeIdentifier> {
+        let mut in_degree = Vec::from_elem(self.node_count(), 0u);
+        for from in range(0, self.node_count()) {
+            for edge in self.connections(from).iter() {
+                *in_degree.get_mut(edge.dest) += 1;
+            }
+        }
+
+        let mut queue = Vec::new();
+        for node in range(0, self.node_count()) {
+            if in_degree[node] == 0 {
+                queue.push(node);
+            }
+        }
+
+        let mut ordered = Vec::new();
+        while queue.len() > 0 {
+            let current = queue.remove(0).unwrap();
+            ordered.push(current);
+            for edge in self.connections(current).iter() {
+                *in_degree.get_mut(edge.dest) -= 1;
+                if in_degree[edge.dest] == 0 {
+                    queue.push(edge.dest);
+                }
+            }
+        }
+
+        if ordered.len() < self.node_count() {
+            // Every unordered node still has an unordered predecessor, so walking
+            // back through predecessors `node_count()` times ends on a cycle.
+            let mut ordered_set = HashSet::new();
+            for node in ordered.iter() {
+                ordered_set.insert(*node);
+            }
+            let mut stuck = range(0, self.node_count()).find(|&n| in_degree[n] > 0).unwrap();
+            for _ in range(0, self.node_count()) {
+                let target = stuck;
+                stuck = range(0, self.node_count()).find(|&from| {
+                    !ordered_set.contains(&from) && self.connections(from).iter().any(|edge| edge.dest == target)
+                }).unwrap();
+            }
+            Err(stuck)
+        } else {
+            Ok(ordered)
+        }
+    }
+
+    /// Returns true if the graph, treated as directed, contains a cycle.
+    pub fn is_cyclic_directed(&self) -> bool {
+        self.toposort().is_err()
+    }
+
+    /// Computes the strongly connected components of the graph using Tarjan's
+    /// algorithm.
+    ///
+    /// Each returned `Vec` is one strongly connected component, containing the
+    /// `NodeIdentifier`s reachable from, and able to reach, each other. An
+    /// iterative DFS is used so that large graphs don't blow the native stack.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeIdentifier>> {
+        let node_count = self.node_count();
+        let mut index = Vec::from_elem(node_count, None);
+        let mut lowlink = Vec::from_elem(node_count, 0u);
+        let mut on_stack = Vec::from_elem(node_count, false);
+        let mut path_stack = Vec::new();
+        let mut next_index = 0u;
+        let mut sccs = Vec::new();
+
+        for start in range(0, node_count) {
+            if index[start].is_some() {
+                continue;
+            }
+
+            // Explicit DFS work stack: the node being visited and the offset of
+            // the next of its edges left to examine.
+            let mut work: Vec<(NodeIdentifier, uint)> = Vec::new();
+            work.push((start, 0));
+
+            while work.len() > 0 {
+                let (node, edge_pos) = *work.last().unwrap();
+
+                if edge_pos == 0 {
+                    *index.get_mut(node) = Some(next_index);
+                    *lowlink.get_mut(node) = next_index;
+                    next_index += 1;
+                    path_stack.push(node);
+                    *on_stack.get_mut(node) = true;
+                }
+
+                let edge_count = self.connections(node).len();
+                if edge_pos < edge_count {
+                    let succ = self.connections(node)[edge_pos].dest;
+                    work.pop();
+                    work.push((node, edge_pos + 1));
+
+                    if index[succ].is_none() {
+                        work.push((succ, 0));
+                    } else if on_stack[succ] {
+                        let succ_index = index[succ].unwrap();
+                        if succ_index < lowlink[node] {
+                            *lowlink.get_mut(node) = succ_index;
+                        }
+                    }
+                } else {
+                    work.pop();
+
+                    match work.last() {
+                        Some(&(parent, _)) => {
+                            if lowlink[node] < lowlink[parent] {
+                                *lowlink.get_mut(parent) = lowlink[node];
+                            }
+                        }
+                        None => {}
+                    }
+
+                    if lowlink[node] == index[node].unwrap() {
+                        let mut component = Vec::new();
+                        loop {
+                            let member = path_stack.pop().unwrap();
+                            *on_stack.get_mut(member) = false;
+                            component.push(member);
+                            if member == node {
+                                break;
+                            }
+                        }
+                        sccs.push(component);
+                    }
+                }
+            }
+        }
+
+        sccs
+    }
+}
+
+impl<'a, T, V: Ord + Clone> Graph<'a, T, V> {
+    /// Computes a minimum spanning tree (or forest, if the graph is disconnected)
+    /// over the graph's edges, treating the graph as undirected.
+    ///
+    /// Uses Kruskal's algorithm: edges are considered in ascending weight order and
+    /// accepted as long as they connect two nodes that aren't already in the same
+    /// set of the union-find subsystem shared with `connected_components`. Returns
+    /// the accepted edges as `(from, to, weight)` tuples.
+    pub fn minimum_spanning_tree(&self) -> Vec<(NodeIdentifier, NodeIdentifier, V)> {
+        let mut all_edges = Vec::new();
+        for from in range(0, self.node_count()) {
+            for edge in self.connections(from).iter() {
+                all_edges.push((edge.data.clone(), from, edge.dest));
+            }
+        }
+        all_edges.sort_by(|a, b| a.ref0().cmp(b.ref0()));
+
+        let mut uf = UnionFind::new(self.node_count());
+        let mut mst = Vec::new();
+        let target_edges = self.node_count() - self.component_count();
+
+        for edge in all_edges.iter() {
+            if mst.len() >= target_edges {
+                break;
+            }
+
+            let (ref weight, from, dest) = *edge;
+            if uf.find(from) != uf.find(dest) {
+                uf.union(from, dest);
+                mst.push((from, dest, weight.clone()));
+            }
+        }
+
+        mst
+    }
+}
+
+impl<'a, T, V> Graph<'a, T, V> {
+    /// Enumerates every simple (loop-free) path from `from` to `to` whose node count
+    /// lies within `[min_nodes, max_nodes]`, or has no upper bound if `max_nodes` is
+    /// `None`.
+    ///
+    /// Paths are discovered depth-first, skipping any node already on the current
+    /// path to guarantee they stay simple. `max_nodes` keeps enumeration tractable on
+    /// dense graphs, where the number of simple paths can otherwise explode.
+    pub fn all_simple_paths(&self, from: NodeIdentifier, to: NodeIdentifier, min_nodes: uint, max_nodes: Option<uint>) -> Vec<Vec<NodeIdentifier>> {
+        let mut paths = Vec::new();
+        let mut visited = HashSet::new();
+        let mut path = Vec::new();
+
+        path.push(from);
+        visited.insert(from);
+        self.all_simple_paths_from(from, to, min_nodes, max_nodes, &mut visited, &mut path, &mut paths);
+
+        paths
+    }
+
+    fn all_simple_paths_from(&self, current: NodeIdentifier, to: NodeIdentifier, min_nodes: uint, max_nodes: Option<uint>, visited: &mut HashSet<NodeIdentifier>, path: &mut Vec<NodeIdentifier>, paths: &mut Vec<Vec<NodeIdentifier>>) {
+        if current == to && path.len() >= min_nodes {
+            paths.push(path.clone());
+        }
+
+        let at_limit = match max_nodes {
+            Some(limit) => path.len() >= limit,
+            None => false
+        };
+        if at_limit {
+            return;
+        }
+
+        for edge in self.connections(current).iter() {
+            if !visited.contains(&edge.dest) {
+                visited.insert(edge.dest);
+                path.push(edge.dest);
+
+                self.all_simple_paths_from(edge.dest, to, min_nodes, max_nodes, visited, path, paths);
+
+                path.pop();
+                visited.remove(&edge.dest);
+            }
+        }
+    }
+}
+
+/// Configuration for `Graph::to_dot_with_config`.
+pub struct DotConfig {
+    /// Whether to render a `label` attribute on each edge, taken from its data.
+    /// Typically turned off for graphs with unweighted `()` edges.
+    pub show_edge_labels: bool,
+}
+
+impl DotConfig {
+    /// The default configuration: edge labels are shown.
+    pub fn new() -> DotConfig {
+        DotConfig { show_edge_labels: true }
+    }
+}
+
+impl<'a, T: fmt::Show, V: fmt::Show> Graph<'a, T, V> {
+    /// Renders the graph in Graphviz DOT format, suitable for piping straight into
+    /// `dot` to produce an image.
+    ///
+    /// Equivalent to `to_dot_with_config` with the default `DotConfig`.
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with_config(&DotConfig::new())
+    }
+
+    /// Like `to_dot`, but with a `DotConfig` controlling what gets rendered.
+    pub fn to_dot_with_config(&self, config: &DotConfig) -> String {
+        let mut out = String::new();
+        out.push_str("digraph {\n");
+
+        for node in range(0, self.node_count()) {
+            let label = escape_dot_label(format!("{}", self.get(node)).as_slice());
+            out.push_str(format!("    {} [label=\"{}\"];\n", node, label).as_slice());
+        }
+
+        for from in range(0, self.node_count()) {
+            for edge in self.connections(from).iter() {
+                if config.show_edge_labels {
+                    let label = escape_dot_label(format!("{}", edge.data).as_slice());
+                    out.push_str(format!("    {} -> {} [label=\"{}\"];\n", from, edge.dest, label).as_slice());
+                } else {
+                    out.push_str(format!("    {} -> {};\n", from, edge.dest).as_slice());
+                }
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+// Escapes backslashes and double quotes so node/edge data can't break out of a DOT label.
+fn escape_dot_label(label: &str) -> String {
+    label.replace("\\", "\\\\").as_slice().replace("\"", "\\\"")
+}
+
+// Disjoint-set structure used by algorithms that need to group nodes into sets,
+// such as `connected_components` and `minimum_spanning_tree`.
+struct UnionFind {
+    parent: Vec<uint>,
+    rank: Vec<uint>,
+}
+
+impl UnionFind {
+    fn new(size: uint) -> UnionFind {
+        UnionFind {
+            parent: Vec::from_fn(size, |i| i),
+            rank: Vec::from_elem(size, 0u),
+        }
+    }
+
+    fn find(&mut self, node: uint) -> uint {
+        if self.parent[node] != node {
+            let root = self.find(self.parent[node]);
+            *self.parent.get_mut(node) = root;
+        }
+        self.parent[node]
+    }
+
+    fn union(&mut self, a: uint, b: uint) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return;
+        }
+
+        if self.rank[root_a] < self.rank[root_b] {
+            *self.parent.get_mut(root_a) = root_b;
+        } else if self.rank[root_a] > self.rank[root_b] {
+            *self.parent.get_mut(root_b) = root_a;
+        } else {
+            *self.parent.get_mut(root_b) = root_a;
+            *self.rank.get_mut(root_a) += 1;
+        }
+    }
 }
 
 
@@ -242,15 +600,24 @@ pub struct ShortestPathResult<V> {
 
 
 impl<'a, T, V: Clone + Ord + PartialOrd + Eq + Unsigned> Graph<'a, T, V> {
-    /// Finds the shortest path between two nodes.
+    /// Computes the shortest-path cost from `start` to every node in the graph.
     ///
-    /// Uses Dijkstra's shortest path to connect two nodes with the least cost.
-    /// Edge data is used as the "cost" metric, where a greater value incurs more cost.
+    /// Uses Dijkstra's algorithm, with edge data as the "cost" metric, where a
+    /// greater value incurs more cost. Stops early only if `goal` is given and
+    /// reached, otherwise runs to completion over every reachable node.
     ///
-    /// The returned `Option` has `Some` if a route can be calculated, containing a structure
-    /// that has the path taken and the total cost of the path or `None` if there was no
-    /// path between nodes.
-    pub fn shortest_path(&self, from_node: NodeIdentifier, to_node: NodeIdentifier) -> Option<ShortestPathResult<V>> {
+    /// Returns a vector indexed by `NodeIdentifier`, where each entry is `Some` with
+    /// the shortest-path cost to that node, or `None` if it wasn't reached. When
+    /// `goal` stops the search early, entries for nodes not yet settled hold the
+    /// best cost found so far, which may be greater than the shortest one.
+    pub fn dijkstra(&self, start: NodeIdentifier, goal: Option<NodeIdentifier>) -> Vec<Option<V>> {
+        let (dist, _) = self.dijkstra_with_prev(start, goal);
+        dist
+    }
+
+    // Shared Dijkstra engine: computes both the distance map used by `dijkstra`
+    // and the `prev` map `shortest_path` needs to reconstruct a path.
+    fn dijkstra_with_prev(&self, start: NodeIdentifier, goal: Option<NodeIdentifier>) -> (Vec<Option<V>>, Vec<Option<NodeIdentifier>>) {
         // Current shortest path to node
         let mut dist = Vec::from_elem(self.node_count(), None);
         let mut prev = Vec::from_elem(self.node_count(), None);
@@ -258,14 +625,18 @@ impl<'a, T, V: Clone + Ord + PartialOrd + Eq + Unsigned> Graph<'a, T, V> {
         // Current nodes to consider
         let mut pq = PriorityQueue::new();
 
-        *dist.get_mut(from_node) = Some(zero::<V>());
-        pq.push(NodeCost { cost: zero::<V>(), node: from_node });
+        *dist.get_mut(start) = Some(zero::<V>());
+        pq.push(NodeCost { cost: zero::<V>(), node: start });
         while pq.len() > 0 {
             // Get the current lowest cost node on the fringe
             let current = pq.pop().unwrap();
 
             // If we've found our target, break out as we won't find a shorter path
-            if current.node == to_node {
+            let reached_goal = match goal {
+                Some(goal_node) => goal_node == current.node,
+                None => false
+            };
+            if reached_goal {
                 break
             }
 
@@ -293,6 +664,20 @@ impl<'a, T, V: Clone + Ord + PartialOrd + Eq + Unsigned> Graph<'a, T, V> {
             }
         }
 
+        (dist, prev)
+    }
+
+    /// Finds the shortest path between two nodes.
+    ///
+    /// Uses Dijkstra's shortest path to connect two nodes with the least cost.
+    /// Edge data is used as the "cost" metric, where a greater value incurs more cost.
+    ///
+    /// The returned `Option` has `Some` if a route can be calculated, containing a structure
+    /// that has the path taken and the total cost of the path or `None` if there was no
+    /// path between nodes.
+    pub fn shortest_path(&self, from_node: NodeIdentifier, to_node: NodeIdentifier) -> Option<ShortestPathResult<V>> {
+        let (dist, prev) = self.dijkstra_with_prev(from_node, Some(to_node));
+
         // Calculate path back based on shortest paths
         if dist[to_node].is_some() {
             let mut path = Vec::new();
@@ -319,9 +704,75 @@ impl<'a, T, V: Clone + Ord + PartialOrd + Eq + Unsigned> Graph<'a, T, V> {
 }
 
 
+impl<'a, T, V: Clone + Ord + PartialOrd + Eq + Unsigned> Graph<'a, T, V> {
+    /// Finds a path between `from_node` and a goal node using the A* search algorithm.
+    ///
+    /// Like `shortest_path`, this treats edge data as a "cost" metric, but guides the
+    /// search towards the goal using `estimate_cost`, a per-node heuristic. `is_goal`
+    /// is used to recognise when a satisfactory node has been reached.
+    ///
+    /// `estimate_cost` must be admissible -- it must never overestimate the true
+    /// remaining cost to any goal node -- otherwise the path found is not guaranteed
+    /// to be the cheapest one.
+    pub fn astar(&self, from_node: NodeIdentifier, is_goal: |NodeIdentifier| -> bool, estimate_cost: |NodeIdentifier| -> V) -> Option<ShortestPathResult<V>> {
+        // Best-known cost from from_node to each node (the "g score")
+        let mut dist = Vec::from_elem(self.node_count(), None);
+        let mut prev = Vec::from_elem(self.node_count(), None);
+
+        // Current nodes to consider, ordered by f score (g score + heuristic)
+        let mut pq = PriorityQueue::new();
+
+        *dist.get_mut(from_node) = Some(zero::<V>());
+        pq.push(NodeCost { cost: estimate_cost(from_node), node: from_node });
+
+        while pq.len() > 0 {
+            let current = pq.pop().unwrap();
+
+            if is_goal(current.node) {
+                let mut path = Vec::new();
+                let mut node = current.node;
+                while node != from_node {
+                    path.push(node);
+                    let next = prev[node].unwrap();
+                    node = next;
+                }
+                path.push(from_node);
+                path.reverse();
+                return Some(ShortestPathResult {
+                    cost: dist[current.node].clone().unwrap(),
+                    path: path
+                });
+            }
+
+            // g score for the node popped off the fringe
+            let current_cost = dist[current.node].clone().unwrap();
+
+            for edge in self.connections(current.node).iter() {
+                let cost_to_node = current_cost + edge.data;
+
+                let should_update_cost = match dist[edge.dest] {
+                    Some(ref cost) => &cost_to_node < cost,
+                    None => true
+                };
+
+                if should_update_cost {
+                    *dist.get_mut(edge.dest) = Some(cost_to_node.clone());
+                    *prev.get_mut(edge.dest) = Some(current.node);
+                    pq.push(NodeCost { cost: cost_to_node.clone() + estimate_cost(edge.dest), node: edge.dest });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+
 #[cfg(test)]
 mod test {
+    use super::DotConfig;
     use super::Graph;
+    use super::UnionFind;
     use std::collections::HashSet;
 
     #[test]
@@ -479,5 +930,266 @@ mod test {
         assert!(g.shortest_path(n1, n2).is_none());
     }
 
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let mut g = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], 5u),
+            (nodes[1], nodes[2], 5u),
+            (nodes[0], nodes[3], 10u),
+            (nodes[1], nodes[3], 3u)
+        ]);
+
+        let dijkstra = g.shortest_path(nodes[0], nodes[3]).unwrap();
+
+        let goal = nodes[3];
+        let astar = g.astar(nodes[0], |n| n == goal, |_| 0u).unwrap();
+
+        assert!(astar.cost == dijkstra.cost);
+        assert!(astar.path == dijkstra.path);
+    }
+
+    #[test]
+    fn test_astar_no_path() {
+        let mut g: Graph<int, uint> = Graph::new();
+        let n1 = g.insert(0i);
+        let n2 = g.insert(1i);
+
+        assert!(g.astar(n1, |n| n == n2, |_| 0u).is_none());
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3, 4]);
+        // nodes[0] <-> nodes[1], and nodes[2] <-> nodes[3] are connected,
+        // nodes[4] stands alone.
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[2], nodes[3], ())
+        ]);
+
+        let labels = g.connected_components();
+        assert!(labels[0] == labels[1]);
+        assert!(labels[2] == labels[3]);
+        assert!(labels[0] != labels[2]);
+        assert!(labels[0] != labels[4]);
+        assert!(labels[2] != labels[4]);
+        assert!(g.component_count() == 3);
+    }
+
+    #[test]
+    fn test_connected_components_undirected() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1]);
+        // Only a single directed edge, but components should still treat this
+        // as connecting both nodes.
+        g.connect(nodes[1], nodes[0], ());
+
+        assert!(g.component_count() == 1);
+    }
+
+    #[test]
+    fn test_toposort() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[0], nodes[2], ()),
+            (nodes[1], nodes[3], ()),
+            (nodes[2], nodes[3], ())
+        ]);
+
+        let ordered = g.toposort().unwrap();
+        assert!(ordered.len() == 4);
+
+        let mut position = Vec::from_elem(4, 0u);
+        for (i, node) in ordered.iter().enumerate() {
+            *position.get_mut(*node) = i;
+        }
+        assert!(position[nodes[0]] < position[nodes[1]]);
+        assert!(position[nodes[0]] < position[nodes[2]]);
+        assert!(position[nodes[1]] < position[nodes[3]]);
+        assert!(position[nodes[2]] < position[nodes[3]]);
+
+        assert!(!g.is_cyclic_directed());
+    }
+
+    #[test]
+    fn test_toposort_cycle() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2]);
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[1], nodes[2], ()),
+            (nodes[2], nodes[0], ())
+        ]);
+
+        assert!(g.toposort().is_err());
+        assert!(g.is_cyclic_directed());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        // nodes[0], nodes[1], nodes[2] form a cycle; nodes[3] is reachable
+        // from the cycle but can't reach back.
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[1], nodes[2], ()),
+            (nodes[2], nodes[0], ()),
+            (nodes[2], nodes[3], ())
+        ]);
+
+        let mut sccs = g.strongly_connected_components();
+        for scc in sccs.iter_mut() {
+            scc.sort();
+        }
+        sccs.sort_by(|a, b| a.len().cmp(&b.len()));
+
+        assert!(sccs.len() == 2);
+        assert!(sccs[0] == vec![nodes[3]]);
+        assert!(sccs[1] == vec![nodes[0], nodes[1], nodes[2]]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_acyclic() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2]);
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[1], nodes[2], ())
+        ]);
+
+        let sccs = g.strongly_connected_components();
+        assert!(sccs.len() == 3);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let mut g = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], 1u),
+            (nodes[1], nodes[2], 2u),
+            (nodes[2], nodes[3], 3u),
+            (nodes[0], nodes[3], 10u),
+            (nodes[1], nodes[3], 4u)
+        ]);
+
+        let mst = g.minimum_spanning_tree();
+        assert!(mst.len() == 3);
+
+        let total_weight: uint = mst.iter().map(|edge| *edge.ref2()).fold(0u, |a, b| a + b);
+        assert!(total_weight == 6u);
+
+        // No cycle should be introduced: each MST edge must join two nodes that
+        // earlier MST edges haven't already put in the same set.
+        let mut uf = UnionFind::new(g.node_count());
+        for edge in mst.iter() {
+            let (from, dest, _) = *edge;
+            assert!(uf.find(from) != uf.find(dest));
+            uf.union(from, dest);
+        }
+    }
+
+    #[test]
+    fn test_all_simple_paths() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[1], nodes[3], ()),
+            (nodes[0], nodes[2], ()),
+            (nodes[2], nodes[3], ()),
+            (nodes[1], nodes[2], ())
+        ]);
+
+        let paths = g.all_simple_paths(nodes[0], nodes[3], 1, None);
+        assert!(paths.len() == 3);
+        assert!(paths.contains(&vec![nodes[0], nodes[1], nodes[3]]));
+        assert!(paths.contains(&vec![nodes[0], nodes[2], nodes[3]]));
+        assert!(paths.contains(&vec![nodes[0], nodes[1], nodes[2], nodes[3]]));
+    }
+
+    #[test]
+    fn test_all_simple_paths_bounds() {
+        let mut g: Graph<int, ()> = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], ()),
+            (nodes[1], nodes[3], ()),
+            (nodes[0], nodes[2], ()),
+            (nodes[2], nodes[3], ()),
+            (nodes[1], nodes[2], ())
+        ]);
+
+        // Bounding to at most 3 nodes should exclude the 4-node path.
+        let paths = g.all_simple_paths(nodes[0], nodes[3], 1, Some(3));
+        assert!(paths.len() == 2);
+
+        // Requiring at least 4 nodes should exclude both 3-node paths.
+        let paths = g.all_simple_paths(nodes[0], nodes[3], 4, None);
+        assert!(paths.len() == 1);
+        assert!(paths[0] == vec![nodes[0], nodes[1], nodes[2], nodes[3]]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut g = Graph::new();
+        let nodes = g.insert_all([0i, 1]);
+        g.connect(nodes[0], nodes[1], 5i);
+
+        let dot = g.to_dot();
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.as_slice().contains("0 [label=\"0\"];"));
+        assert!(dot.as_slice().contains("1 [label=\"1\"];"));
+        assert!(dot.as_slice().contains("0 -> 1 [label=\"5\"];"));
+    }
+
+    #[test]
+    fn test_to_dot_without_edge_labels() {
+        let mut g = Graph::new();
+        let nodes = g.insert_all([0i, 1]);
+        g.connect(nodes[0], nodes[1], ());
+
+        let config = DotConfig { show_edge_labels: false };
+        let dot = g.to_dot_with_config(&config);
+        assert!(dot.as_slice().contains("0 -> 1;"));
+        assert!(!dot.as_slice().contains("label=\"()\""));
+    }
+
+    #[test]
+    fn test_dijkstra_all_distances() {
+        let mut g = Graph::new();
+        let nodes = g.insert_all([0i, 1, 2, 3]);
+        g.connect_all([
+            (nodes[0], nodes[1], 5u),
+            (nodes[1], nodes[2], 5u),
+            (nodes[0], nodes[3], 10u),
+            (nodes[1], nodes[3], 3u)
+        ]);
+
+        let dist = g.dijkstra(nodes[0], None);
+        assert!(dist[nodes[0]] == Some(0u));
+        assert!(dist[nodes[1]] == Some(5u));
+        assert!(dist[nodes[2]] == Some(10u));
+        assert!(dist[nodes[3]] == Some(8u));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        let mut g: Graph<int, uint> = Graph::new();
+        let n1 = g.insert(0i);
+        let n2 = g.insert(1i);
+
+        let dist = g.dijkstra(n1, None);
+        assert!(dist[n1] == Some(0u));
+        assert!(dist[n2] == None);
+    }
+
 }
 